@@ -1,62 +1,214 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver};
 
 use eframe::{egui, App};
-use rrrocket::ReplayParser;
+use egui_dock::{DockArea, DockState, Style, TabViewer};
+use reqwest::Client;
+use rrrocket::{NetworkMode, PlayerSummary, ReplayParser, ReplaySummary};
+use serde_json::Value;
 
-struct RrrocketGui {
+fn next_tab_id() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Where a tab's currently displayed replay came from, so "Reload" and "Save to file…"
+/// know how to behave.
+enum ReplaySource {
+    Path(PathBuf),
+    Url(String),
+}
+
+impl ReplaySource {
+    fn label(&self) -> String {
+        match self {
+            ReplaySource::Path(path) => path.display().to_string(),
+            ReplaySource::Url(url) => url.clone(),
+        }
+    }
+}
+
+/// A successfully parsed replay's header summary, published to the shared registry so the
+/// "Compare" tab can look it up by id without reaching across tabs directly.
+#[derive(Clone)]
+struct ReplayRecord {
+    title: String,
+    summary: ReplaySummary,
+}
+
+type SharedRegistry = Rc<RefCell<HashMap<u64, ReplayRecord>>>;
+
+/// A message sent back from the background thread doing a URL download, so the UI thread
+/// never blocks waiting on the network.
+enum UrlLoadMessage {
+    Progress {
+        downloaded: u64,
+        total: Option<u64>,
+    },
+    Done(anyhow::Result<(boxcars::Replay, Option<String>)>),
+}
+
+/// One opened replay: its own parser options, decoded JSON, error/status, and tree state.
+struct ReplayTab {
+    id: u64,
     parser: ReplayParser,
-    last_path: Option<PathBuf>,
-    last_json: Option<String>,
+    last_source: Option<ReplaySource>,
+    last_value: Option<Value>,
     last_error: Option<String>,
     last_status: Option<String>,
+    show_url_dialog: bool,
+    url_input: String,
+    search_query: String,
+    selected_path: Option<String>,
+    selected_value: Option<Value>,
+    url_job: Option<Receiver<UrlLoadMessage>>,
+    url_progress: Option<(u64, Option<u64>)>,
 }
 
-impl RrrocketGui {
-    fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+impl ReplayTab {
+    fn new() -> Self {
         Self {
+            id: next_tab_id(),
             parser: ReplayParser::default(),
-            last_path: None,
-            last_json: None,
+            last_source: None,
+            last_value: None,
             last_error: None,
             last_status: None,
+            show_url_dialog: false,
+            url_input: String::new(),
+            search_query: String::new(),
+            selected_path: None,
+            selected_value: None,
+            url_job: None,
+            url_progress: None,
         }
     }
 
-    fn load_path(&mut self, path: PathBuf) {
-        self.last_path = Some(path.clone());
+    fn title(&self) -> String {
+        match &self.last_source {
+            Some(source) => source.label(),
+            None => format!("Untitled replay {}", self.id),
+        }
+    }
+
+    fn load_path(&mut self, path: PathBuf, registry: &SharedRegistry) {
         self.last_status = None;
-        match self.parser.parse_file(&path) {
-            Ok(replay) => match serde_json::to_string_pretty(&replay) {
-                Ok(json) => {
-                    self.last_json = Some(json);
-                    self.last_error = None;
+        match self.parser.parse_path(path) {
+            Ok(parsed) => {
+                self.last_source = Some(ReplaySource::Path(parsed.path.clone()));
+                self.publish(&parsed.replay, registry);
+                self.apply_value(serde_json::to_value(&parsed.replay), parsed.warning);
+            }
+            Err(err) => self.apply_error(err),
+        }
+    }
+
+    /// Kicks off a replay download on a background thread so the UI stays responsive, and
+    /// streams `(bytes_downloaded, total_bytes)` back through the channel as the transfer
+    /// progresses. `poll_url_job` picks up the result once it's ready.
+    fn load_url(&mut self, url: String, http_client: &Client) {
+        self.last_status = Some(format!("Downloading {url}…"));
+        self.last_error = None;
+        self.url_progress = None;
+        self.last_source = Some(ReplaySource::Url(url.clone()));
+
+        let (tx, rx) = mpsc::channel();
+        let client = http_client.clone();
+        let parser = self.parser.clone();
+        std::thread::spawn(move || {
+            let progress_tx = tx.clone();
+            let result = parser.parse_url_blocking(&client, &url, move |downloaded, total| {
+                let _ = progress_tx.send(UrlLoadMessage::Progress { downloaded, total });
+            });
+            let _ = tx.send(UrlLoadMessage::Done(result));
+        });
+        self.url_job = Some(rx);
+    }
+
+    /// Drains any messages from an in-flight URL download without blocking. Called once
+    /// per frame so the progress label stays current and the result is applied the moment
+    /// the download finishes.
+    fn poll_url_job(&mut self, registry: &SharedRegistry) {
+        let Some(rx) = &self.url_job else {
+            return;
+        };
+
+        let mut done = None;
+        for message in rx.try_iter() {
+            match message {
+                UrlLoadMessage::Progress { downloaded, total } => {
+                    self.url_progress = Some((downloaded, total));
                 }
-                Err(err) => {
-                    self.last_error = Some(format!("Failed to serialize replay: {err}"));
-                    self.last_json = None;
+                UrlLoadMessage::Done(result) => done = Some(result),
+            }
+        }
+
+        if let Some(result) = done {
+            self.url_job = None;
+            self.url_progress = None;
+            self.last_status = None;
+            match result {
+                Ok((replay, warning)) => {
+                    self.publish(&replay, registry);
+                    self.apply_value(serde_json::to_value(&replay), warning);
                 }
-            },
+                Err(err) => self.apply_error(err),
+            }
+        }
+    }
+
+    fn publish(&self, replay: &boxcars::Replay, registry: &SharedRegistry) {
+        let title = self.title();
+        let summary = ReplaySummary::from_replay(PathBuf::from(&title), replay);
+        registry
+            .borrow_mut()
+            .insert(self.id, ReplayRecord { title, summary });
+    }
+
+    fn apply_value(&mut self, value: Result<Value, serde_json::Error>, warning: Option<String>) {
+        match value {
+            Ok(value) => {
+                self.last_value = Some(value);
+                self.last_error = None;
+                self.last_status = warning;
+                self.selected_path = None;
+                self.selected_value = None;
+            }
             Err(err) => {
-                self.last_error = Some(format!("{err:#}"));
-                self.last_json = None;
+                self.last_error = Some(format!("Failed to serialize replay: {err}"));
+                self.last_value = None;
             }
         }
     }
 
-    fn reparse_last(&mut self) {
-        if let Some(path) = self.last_path.clone() {
-            self.load_path(path);
+    fn apply_error(&mut self, err: anyhow::Error) {
+        self.last_error = Some(format!("{err:#}"));
+        self.last_value = None;
+    }
+
+    fn reparse_last(&mut self, http_client: &Client, registry: &SharedRegistry) {
+        match self.last_source.take() {
+            Some(ReplaySource::Path(path)) => self.load_path(path, registry),
+            Some(ReplaySource::Url(url)) => self.load_url(url, http_client),
+            None => {}
         }
     }
 
     fn save_json(&mut self) {
-        let Some(json) = self.last_json.as_ref() else {
+        let Some(value) = self.last_value.as_ref() else {
+            return;
+        };
+        let Ok(json) = serde_json::to_string_pretty(value) else {
             return;
         };
 
         let mut dialog = rfd::FileDialog::new().add_filter("JSON", &["json"]);
-        if let Some(path) = &self.last_path {
+        if let Some(ReplaySource::Path(path)) = &self.last_source {
             if let Some(parent) = path.parent() {
                 dialog = dialog.set_directory(parent);
             }
@@ -83,15 +235,16 @@ impl RrrocketGui {
             }
         }
     }
-}
 
-impl App for RrrocketGui {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading("rrrocket GUI");
-            ui.label("Parse Rocket League replays and inspect their decoded JSON.");
-            ui.add_space(8.0);
+    fn ui(&mut self, ui: &mut egui::Ui, http_client: &Client, registry: &SharedRegistry) {
+        self.poll_url_job(registry);
+        if self.url_job.is_some() {
+            // Keep repainting even with no user input so the progress label and the
+            // eventual result are picked up promptly once the background thread reports in.
+            ui.ctx().request_repaint();
+        }
 
+        ui.push_id(self.id, |ui| {
             let mut parser_changed = false;
             ui.horizontal(|ui| {
                 let mut crc = self.parser.crc_check();
@@ -99,11 +252,22 @@ impl App for RrrocketGui {
                     self.parser.set_crc_check(crc);
                     parser_changed = true;
                 }
+            });
 
-                let mut network = self.parser.network_parse();
-                if ui.checkbox(&mut network, "Parse network data").changed() {
-                    self.parser.set_network_parse(network);
-                    parser_changed = true;
+            ui.horizontal(|ui| {
+                ui.label("Network data:");
+                let mut network_mode = self.parser.network_mode();
+                parser_changed |= ui
+                    .radio_value(&mut network_mode, NetworkMode::Skip, "Skip")
+                    .changed();
+                parser_changed |= ui
+                    .radio_value(&mut network_mode, NetworkMode::Always, "Always")
+                    .changed();
+                parser_changed |= ui
+                    .radio_value(&mut network_mode, NetworkMode::Tolerant, "Tolerant")
+                    .changed();
+                if parser_changed {
+                    self.parser.set_network_mode(network_mode);
                 }
             });
 
@@ -114,22 +278,63 @@ impl App for RrrocketGui {
                         .add_filter("Rocket League Replay", &["replay"])
                         .pick_file()
                     {
-                        self.load_path(path);
+                        self.load_path(path, registry);
                     }
                 }
 
-                if ui.button("Reload").clicked() {
-                    self.reparse_last();
+                if ui.button("Open from URL…").clicked() {
+                    self.show_url_dialog = true;
+                }
+
+                if ui.add_enabled(self.url_job.is_none(), egui::Button::new("Reload")).clicked() {
+                    self.reparse_last(http_client, registry);
                 }
             });
 
             if parser_changed {
-                self.reparse_last();
+                self.reparse_last(http_client, registry);
+            }
+
+            if self.show_url_dialog {
+                let mut open = true;
+                let mut submitted = false;
+                egui::Window::new("Open from URL")
+                    .id(egui::Id::new(("open_from_url", self.id)))
+                    .open(&mut open)
+                    .collapsible(false)
+                    .show(ui.ctx(), |ui| {
+                        ui.label("URL to a .replay file:");
+                        let response = ui.text_edit_singleline(&mut self.url_input);
+                        submitted |= response.lost_focus()
+                            && ui.input(|input| input.key_pressed(egui::Key::Enter));
+                        ui.horizontal(|ui| {
+                            submitted |= ui.button("Load").clicked();
+                            if ui.button("Cancel").clicked() {
+                                open = false;
+                            }
+                        });
+                    });
+
+                if submitted && !self.url_input.trim().is_empty() && self.url_job.is_none() {
+                    let url = self.url_input.trim().to_owned();
+                    self.load_url(url, http_client);
+                    open = false;
+                }
+
+                self.show_url_dialog = open;
+            }
+
+            if let Some((downloaded, total)) = self.url_progress {
+                let text = match total {
+                    Some(total) => format!("Downloading… {downloaded}/{total} bytes"),
+                    None => format!("Downloading… {downloaded} bytes"),
+                };
+                ui.colored_label(egui::Color32::from_rgb(240, 181, 51), text);
             }
 
             ui.add_space(8.0);
-            if let Some(path) = &self.last_path {
-                ui.label(format!("Selected replay: {}", path.display()));
+            if let Some(source) = &self.last_source {
+                ui.label(format!("Selected replay: {}", source.label()));
             } else {
                 ui.label("Select a replay file to begin.");
             }
@@ -142,13 +347,14 @@ impl App for RrrocketGui {
                 ui.colored_label(egui::Color32::from_rgb(38, 166, 91), status);
             }
 
-            if self.last_json.is_some() {
+            if let Some(root) = &self.last_value {
                 ui.separator();
                 ui.horizontal(|ui| {
                     ui.heading("Replay JSON");
-                    if ui.button("Copy to clipboard").clicked() {
-                        if let Some(json) = &self.last_json {
-                            ctx.output_mut(|out| out.copied_text = json.clone());
+                    if ui.button("Copy JSON").clicked() {
+                        let value = self.selected_value.as_ref().unwrap_or(root);
+                        if let Ok(json) = serde_json::to_string_pretty(value) {
+                            ui.ctx().output_mut(|out| out.copied_text = json);
                         }
                     }
                     if ui.button("Save to file…").clicked() {
@@ -156,24 +362,460 @@ impl App for RrrocketGui {
                     }
                 });
 
-                if let Some(json) = &mut self.last_json {
-                    egui::ScrollArea::vertical()
-                        .id_source("replay_json")
-                        .show(ui, |ui| {
-                            ui.add(
-                                egui::TextEdit::multiline(json)
-                                    .code_editor()
-                                    .desired_rows(30)
-                                    .desired_width(f32::INFINITY)
-                                    .interactive(false),
-                            );
-                        });
+                ui.horizontal(|ui| {
+                    ui.label("Search:");
+                    ui.text_edit_singleline(&mut self.search_query);
+                    if ui.button("Clear").clicked() {
+                        self.search_query.clear();
+                    }
+                });
+                if self.selected_value.is_some() {
+                    ui.label(
+                        "Copy JSON copies the selected node; click it again to clear the selection and copy the whole replay.",
+                    );
+                }
+
+                ui.add_space(4.0);
+                let filter = self.search_query.to_lowercase();
+                egui::ScrollArea::vertical()
+                    .id_source("replay_json_tree")
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        render_json_node(
+                            ui,
+                            "replay",
+                            "root",
+                            root,
+                            &filter,
+                            &mut self.selected_path,
+                            &mut self.selected_value,
+                        );
+                    });
+            }
+        });
+    }
+}
+
+/// A side-by-side comparison of two already-parsed replays' header fields.
+struct CompareTab {
+    left: Option<u64>,
+    right: Option<u64>,
+}
+
+impl CompareTab {
+    fn new() -> Self {
+        Self {
+            left: None,
+            right: None,
+        }
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, registry: &SharedRegistry) {
+        let records = registry.borrow();
+        let mut ids: Vec<u64> = records.keys().copied().collect();
+        ids.sort_unstable();
+
+        if ids.len() < 2 {
+            ui.label("Open and parse at least two replays to compare them.");
+            return;
+        }
+
+        let selected_title = |id: Option<u64>| {
+            id.and_then(|id| records.get(&id))
+                .map(|record| record.title.clone())
+                .unwrap_or_else(|| "Select a replay…".to_owned())
+        };
+
+        egui::ComboBox::from_label("Left replay")
+            .selected_text(selected_title(self.left))
+            .show_ui(ui, |ui| {
+                for id in &ids {
+                    ui.selectable_value(&mut self.left, Some(*id), &records[id].title);
+                }
+            });
+
+        egui::ComboBox::from_label("Right replay")
+            .selected_text(selected_title(self.right))
+            .show_ui(ui, |ui| {
+                for id in &ids {
+                    ui.selectable_value(&mut self.right, Some(*id), &records[id].title);
+                }
+            });
+
+        ui.separator();
+
+        let (Some(left_id), Some(right_id)) = (self.left, self.right) else {
+            ui.label("Select two replays above to see their differences.");
+            return;
+        };
+        let (Some(left), Some(right)) = (records.get(&left_id), records.get(&right_id)) else {
+            return;
+        };
+
+        egui::Grid::new("compare_grid").striped(true).show(ui, |ui| {
+            ui.strong("Field");
+            ui.strong(&left.title);
+            ui.strong(&right.title);
+            ui.end_row();
+
+            for (field, left_value, right_value) in diff_rows(&left.summary, &right.summary) {
+                ui.label(field);
+                if left_value != right_value {
+                    let color = egui::Color32::from_rgb(240, 181, 51);
+                    ui.colored_label(color, &left_value);
+                    ui.colored_label(color, &right_value);
+                } else {
+                    ui.label(&left_value);
+                    ui.label(&right_value);
                 }
+                ui.end_row();
             }
         });
     }
 }
 
+fn diff_rows(left: &ReplaySummary, right: &ReplaySummary) -> Vec<(&'static str, String, String)> {
+    fn opt<T: ToString>(value: &Option<T>) -> String {
+        value
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| "—".to_owned())
+    }
+
+    fn players(players: &[PlayerSummary]) -> String {
+        players
+            .iter()
+            .map(|player| format!("{} ({})", player.name, player.score))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    vec![
+        ("Map", opt(&left.map_name), opt(&right.map_name)),
+        ("Team size", opt(&left.team_size), opt(&right.team_size)),
+        (
+            "Team 0 score",
+            opt(&left.team_0_score),
+            opt(&right.team_0_score),
+        ),
+        (
+            "Team 1 score",
+            opt(&left.team_1_score),
+            opt(&right.team_1_score),
+        ),
+        (
+            "Match length (s)",
+            opt(&left.match_length_seconds),
+            opt(&right.match_length_seconds),
+        ),
+        ("Date", opt(&left.date), opt(&right.date)),
+        ("Players", players(&left.players), players(&right.players)),
+    ]
+}
+
+#[cfg(test)]
+mod diff_rows_tests {
+    use super::*;
+
+    fn summary(team_0_score: i32, players: Vec<PlayerSummary>) -> ReplaySummary {
+        ReplaySummary {
+            path: PathBuf::from("match.replay"),
+            map_name: Some("Stadium_P".to_owned()),
+            team_size: Some(3),
+            team_0_score: Some(team_0_score),
+            team_1_score: Some(5),
+            players,
+            match_length_seconds: Some(300.0),
+            date: Some("2020-01-01 12:00:00".to_owned()),
+        }
+    }
+
+    #[test]
+    fn diff_rows_reports_identical_values_for_matching_summaries() {
+        let left = summary(2, vec![PlayerSummary { name: "Squishy".to_owned(), score: 400 }]);
+        let right = summary(2, vec![PlayerSummary { name: "Squishy".to_owned(), score: 400 }]);
+
+        let rows = diff_rows(&left, &right);
+
+        let (_, left_score, right_score) = rows
+            .iter()
+            .find(|(field, _, _)| *field == "Team 0 score")
+            .unwrap();
+        assert_eq!(left_score, right_score);
+    }
+
+    #[test]
+    fn diff_rows_highlights_differing_scores() {
+        let left = summary(2, vec![]);
+        let right = summary(4, vec![]);
+
+        let rows = diff_rows(&left, &right);
+
+        let (_, left_score, right_score) = rows
+            .iter()
+            .find(|(field, _, _)| *field == "Team 0 score")
+            .unwrap();
+        assert_ne!(left_score, right_score);
+        assert_eq!(left_score, "2");
+        assert_eq!(right_score, "4");
+    }
+
+    #[test]
+    fn diff_rows_flattens_and_diffs_player_rosters() {
+        let left = summary(2, vec![PlayerSummary { name: "Squishy".to_owned(), score: 400 }]);
+        let right = summary(
+            2,
+            vec![
+                PlayerSummary { name: "Squishy".to_owned(), score: 400 },
+                PlayerSummary { name: "Sadge".to_owned(), score: 250 },
+            ],
+        );
+
+        let rows = diff_rows(&left, &right);
+
+        let (_, left_players, right_players) = rows
+            .iter()
+            .find(|(field, _, _)| *field == "Players")
+            .unwrap();
+        assert_eq!(left_players, "Squishy (400)");
+        assert_eq!(right_players, "Squishy (400), Sadge (250)");
+        assert_ne!(left_players, right_players);
+    }
+}
+
+/// Renders a single scalar value the way it would appear inline next to its key.
+fn scalar_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "null".to_owned(),
+        other => other.to_string(),
+    }
+}
+
+/// Returns true if `value` (or any of its descendants, with `key` as its own key) contains
+/// `filter` (already lowercased). An empty filter matches everything.
+fn value_matches(key: Option<&str>, value: &Value, filter: &str) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    if key.is_some_and(|k| k.to_lowercase().contains(filter)) {
+        return true;
+    }
+    match value {
+        Value::Object(map) => map.iter().any(|(k, v)| value_matches(Some(k), v, filter)),
+        Value::Array(arr) => arr.iter().any(|v| value_matches(None, v, filter)),
+        _ => scalar_to_string(value).to_lowercase().contains(filter),
+    }
+}
+
+/// Recursively renders a `serde_json::Value` as collapsible tree nodes, filtering out
+/// subtrees that don't match `filter` and letting the user select a leaf to copy (clicking
+/// the selected leaf again clears the selection).
+fn render_json_node(
+    ui: &mut egui::Ui,
+    label: &str,
+    node_id: &str,
+    value: &Value,
+    filter: &str,
+    selected_path: &mut Option<String>,
+    selected_value: &mut Option<Value>,
+) {
+    if !value_matches(Some(label), value, filter) {
+        return;
+    }
+
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            egui::CollapsingHeader::new(format!("{label}  {{{}}}", map.len()))
+                .id_source(node_id)
+                .default_open(!filter.is_empty())
+                .show(ui, |ui| {
+                    for (key, child) in map {
+                        let child_id = format!("{node_id}/{key}");
+                        render_json_node(
+                            ui,
+                            key,
+                            &child_id,
+                            child,
+                            filter,
+                            selected_path,
+                            selected_value,
+                        );
+                    }
+                });
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            egui::CollapsingHeader::new(format!("{label}  [{}]", arr.len()))
+                .id_source(node_id)
+                .default_open(!filter.is_empty())
+                .show(ui, |ui| {
+                    for (index, child) in arr.iter().enumerate() {
+                        let child_label = index.to_string();
+                        let child_id = format!("{node_id}/{index}");
+                        render_json_node(
+                            ui,
+                            &child_label,
+                            &child_id,
+                            child,
+                            filter,
+                            selected_path,
+                            selected_value,
+                        );
+                    }
+                });
+        }
+        leaf => {
+            let text = format!("{label}: {}", scalar_to_string(leaf));
+            let is_match = !filter.is_empty()
+                && (label.to_lowercase().contains(filter)
+                    || scalar_to_string(leaf).to_lowercase().contains(filter));
+            let rich = if is_match {
+                egui::RichText::new(text).color(egui::Color32::from_rgb(240, 181, 51))
+            } else {
+                egui::RichText::new(text)
+            };
+            let response = ui.selectable_label(selected_path.as_deref() == Some(node_id), rich);
+            if response.clicked() {
+                if selected_path.as_deref() == Some(node_id) {
+                    *selected_path = None;
+                    *selected_value = None;
+                } else {
+                    *selected_path = Some(node_id.to_owned());
+                    *selected_value = Some(leaf.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod json_tree_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn scalar_to_string_renders_strings_without_surrounding_quotes() {
+        assert_eq!(scalar_to_string(&json!("hello")), "hello");
+        assert_eq!(scalar_to_string(&json!(42)), "42");
+        assert_eq!(scalar_to_string(&json!(null)), "null");
+    }
+
+    #[test]
+    fn value_matches_treats_an_empty_filter_as_matching_everything() {
+        assert!(value_matches(None, &json!({"MapName": "Stadium_P"}), ""));
+    }
+
+    #[test]
+    fn value_matches_finds_a_key_nested_arbitrarily_deep() {
+        let value = json!({"outer": {"inner": {"MapName": "Stadium_P"}}});
+        assert!(value_matches(None, &value, "mapname"));
+        assert!(!value_matches(None, &value, "nonexistent"));
+    }
+
+    #[test]
+    fn value_matches_finds_a_scalar_value_case_insensitively() {
+        let value = json!({"player": "Squishy"});
+        assert!(value_matches(None, &value, "squishy"));
+        assert!(!value_matches(None, &value, "sadge"));
+    }
+
+    #[test]
+    fn value_matches_recurses_into_array_elements() {
+        let value = json!(["foo", "bar", {"key": "needle"}]);
+        assert!(value_matches(None, &value, "needle"));
+        assert!(!value_matches(None, &value, "missing"));
+    }
+}
+
+/// A single dockable tab: either a replay parser/viewer or the cross-replay comparison.
+enum WorkspaceTab {
+    Replay(ReplayTab),
+    Compare(CompareTab),
+}
+
+impl WorkspaceTab {
+    fn title(&self) -> String {
+        match self {
+            WorkspaceTab::Replay(tab) => tab.title(),
+            WorkspaceTab::Compare(_) => "Compare".to_owned(),
+        }
+    }
+}
+
+struct WorkspaceTabViewer<'a> {
+    http_client: &'a Client,
+    registry: &'a SharedRegistry,
+}
+
+impl TabViewer for WorkspaceTabViewer<'_> {
+    type Tab = WorkspaceTab;
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        tab.title().into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match tab {
+            WorkspaceTab::Replay(replay_tab) => {
+                replay_tab.ui(ui, self.http_client, self.registry);
+            }
+            WorkspaceTab::Compare(compare_tab) => {
+                compare_tab.ui(ui, self.registry);
+            }
+        }
+    }
+
+    fn on_close(&mut self, tab: &mut Self::Tab) -> bool {
+        if let WorkspaceTab::Replay(replay_tab) = tab {
+            self.registry.borrow_mut().remove(&replay_tab.id);
+        }
+        true
+    }
+}
+
+struct RrrocketGui {
+    dock_state: DockState<WorkspaceTab>,
+    http_client: Client,
+    registry: SharedRegistry,
+}
+
+impl RrrocketGui {
+    fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+        Self {
+            dock_state: DockState::new(vec![WorkspaceTab::Replay(ReplayTab::new())]),
+            http_client: rrrocket::build_http_client().unwrap_or_default(),
+            registry: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+}
+
+impl App for RrrocketGui {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::TopBottomPanel::top("workspace_toolbar").show(ctx, |ui| {
+            ui.heading("rrrocket GUI");
+            ui.horizontal(|ui| {
+                if ui.button("New Replay Tab").clicked() {
+                    self.dock_state
+                        .push_to_focused_leaf(WorkspaceTab::Replay(ReplayTab::new()));
+                }
+                if ui.button("New Compare Tab").clicked() {
+                    self.dock_state
+                        .push_to_focused_leaf(WorkspaceTab::Compare(CompareTab::new()));
+                }
+            });
+        });
+
+        let mut viewer = WorkspaceTabViewer {
+            http_client: &self.http_client,
+            registry: &self.registry,
+        };
+
+        DockArea::new(&mut self.dock_state)
+            .style(Style::from_egui(ctx.style().as_ref()))
+            .show(ctx, &mut viewer);
+    }
+}
+
 fn main() -> eframe::Result<()> {
     let options = eframe::NativeOptions::default();
     eframe::run_native(
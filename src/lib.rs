@@ -2,14 +2,45 @@ use anyhow::{Context, Result as AnyResult};
 use boxcars::{CrcCheck, NetworkParse, ParseError, ParserBuilder, Replay};
 use either::Either;
 use glob::glob;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod ndjson;
+mod remote;
+mod summary;
+pub use ndjson::ndjson_progress_writer;
+pub use remote::build_http_client;
+pub use summary::{summarize_paths, summaries_to_csv, summaries_to_json, PlayerSummary, ReplaySummary};
+
+/// Controls how a [`ReplayParser`] handles a replay's network (frame) data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NetworkMode {
+    /// Skip network data entirely and only decode the header. Fast and always succeeds
+    /// as long as the header itself is well-formed.
+    #[default]
+    Skip,
+    /// Require the network data to parse successfully, failing the whole parse otherwise.
+    Always,
+    /// Attempt to parse the network data, but fall back to a header-only replay (with a
+    /// warning attached) in a single pass if the network body fails to decode. Useful for
+    /// replays recorded with a newer patch whose header boxcars already understands.
+    Tolerant,
+}
+
 /// Configurable parser that can decode Rocket League replays from various sources.
 #[derive(Clone, Debug, Default)]
 pub struct ReplayParser {
     crc_check: bool,
-    network_parse: bool,
+    network_mode: NetworkMode,
+}
+
+/// Builds the fallback-warning message for [`ReplayParser::parse_bytes_with_warning`]. Only
+/// [`NetworkMode::Tolerant`] ever warns, and only when the network data didn't come through.
+fn tolerant_fallback_warning(network_mode: NetworkMode, has_network_frames: bool) -> Option<String> {
+    (network_mode == NetworkMode::Tolerant && !has_network_frames)
+        .then(|| "network data failed to parse, falling back to header only".to_owned())
 }
 
 /// Replay data paired with the path it originated from.
@@ -17,6 +48,8 @@ pub struct ReplayParser {
 pub struct ParsedReplay {
     pub path: PathBuf,
     pub replay: Replay,
+    /// Set when [`NetworkMode::Tolerant`] had to fall back to a header-only parse.
+    pub warning: Option<String>,
 }
 
 impl ReplayParser {
@@ -31,9 +64,9 @@ impl ReplayParser {
         self
     }
 
-    /// Enables or disables parsing of the network data contained in a replay.
-    pub fn with_network_parse(mut self, network_parse: bool) -> Self {
-        self.network_parse = network_parse;
+    /// Sets how the parser should handle a replay's network data.
+    pub fn with_network_mode(mut self, network_mode: NetworkMode) -> Self {
+        self.network_mode = network_mode;
         self
     }
 
@@ -42,9 +75,9 @@ impl ReplayParser {
         self.crc_check
     }
 
-    /// Returns whether the parser will decode network data while parsing.
-    pub fn network_parse(&self) -> bool {
-        self.network_parse
+    /// Returns how the parser is configured to handle network data.
+    pub fn network_mode(&self) -> NetworkMode {
+        self.network_mode
     }
 
     /// Mutably sets whether CRC validation should be forced during parsing.
@@ -52,48 +85,117 @@ impl ReplayParser {
         self.crc_check = crc_check;
     }
 
-    /// Mutably sets whether the parser should decode network data while parsing.
-    pub fn set_network_parse(&mut self, network_parse: bool) {
-        self.network_parse = network_parse;
+    /// Mutably sets how the parser should handle a replay's network data.
+    pub fn set_network_mode(&mut self, network_mode: NetworkMode) {
+        self.network_mode = network_mode;
     }
 
-    /// Parses replay bytes according to the configured options.
-    pub fn parse_bytes(&self, data: &[u8]) -> Result<Replay, ParseError> {
+    fn parse_with(&self, data: &[u8], network_parse: NetworkParse) -> Result<Replay, ParseError> {
         ParserBuilder::new(data)
             .with_crc_check(if self.crc_check {
                 CrcCheck::Always
             } else {
                 CrcCheck::OnError
             })
-            .with_network_parse(if self.network_parse {
-                NetworkParse::Always
-            } else {
-                NetworkParse::Never
-            })
+            .with_network_parse(network_parse)
             .parse()
     }
 
-    /// Opens and parses a replay file on disk.
-    pub fn parse_file<P: AsRef<Path>>(&self, path: P) -> AnyResult<Replay> {
-        let path = path.as_ref();
+    /// Parses replay bytes according to the configured options, reporting a warning when
+    /// [`NetworkMode::Tolerant`] had to discard a network parse failure.
+    fn parse_bytes_with_warning(&self, data: &[u8]) -> Result<(Replay, Option<String>), ParseError> {
+        let network_parse = match self.network_mode {
+            NetworkMode::Skip => NetworkParse::Never,
+            NetworkMode::Always => NetworkParse::Always,
+            // boxcars decodes the network data in a single pass and silently drops it
+            // (rather than failing the whole parse) if it errors out partway through.
+            NetworkMode::Tolerant => NetworkParse::IgnoreOnError,
+        };
+
+        let replay = self.parse_with(data, network_parse)?;
+        let warning = tolerant_fallback_warning(self.network_mode, replay.network_frames.is_some());
+
+        Ok((replay, warning))
+    }
+
+    /// Parses replay bytes according to the configured options.
+    pub fn parse_bytes(&self, data: &[u8]) -> Result<Replay, ParseError> {
+        self.parse_bytes_with_warning(data).map(|(replay, _)| replay)
+    }
+
+    fn with_file_bytes<T>(path: &Path, f: impl FnOnce(&[u8]) -> T) -> AnyResult<T> {
         let file = fs::File::open(path)?;
         let mmap = unsafe { memmap2::MmapOptions::new().map(&file) };
 
-        let replay = match mmap {
-            Ok(mapped) => self.parse_bytes(&mapped),
+        match mmap {
+            Ok(mapped) => Ok(f(&mapped)),
             Err(_) => {
                 let data = fs::read(path)?;
-                self.parse_bytes(&data)
+                Ok(f(&data))
             }
-        }?;
+        }
+    }
 
+    /// Opens and parses a replay file on disk.
+    pub fn parse_file<P: AsRef<Path>>(&self, path: P) -> AnyResult<Replay> {
+        let path = path.as_ref();
+        let replay = Self::with_file_bytes(path, |data| self.parse_bytes(data))??;
         Ok(replay)
     }
 
-    /// Parses a replay from disk, returning it alongside the original path.
+    /// Parses a replay from disk, returning it alongside the original path. Carries a
+    /// warning if [`NetworkMode::Tolerant`] had to fall back to a header-only parse.
     pub fn parse_path(&self, path: PathBuf) -> AnyResult<ParsedReplay> {
-        let replay = self.parse_file(&path)?;
-        Ok(ParsedReplay { path, replay })
+        let (replay, warning) =
+            Self::with_file_bytes(&path, |data| self.parse_bytes_with_warning(data))??;
+        Ok(ParsedReplay {
+            path,
+            replay,
+            warning,
+        })
+    }
+
+    /// Parses every path yielded by `paths` in parallel on a rayon thread pool, honoring
+    /// the configured CRC/network-mode settings. Each file's outcome is surfaced
+    /// independently so a single bad replay doesn't abort the rest of the batch.
+    ///
+    /// `max_concurrency` bounds the number of worker threads (the rayon default, based on
+    /// the number of CPUs, is used when `None`). `on_progress` is invoked once per file as
+    /// soon as it finishes, so a CLI or GUI can drive a progress bar without waiting for
+    /// the whole batch to complete.
+    pub fn parse_paths_parallel(
+        &self,
+        paths: impl IntoIterator<Item = AnyResult<PathBuf>>,
+        max_concurrency: Option<usize>,
+        on_progress: Option<&(dyn Fn(&AnyResult<ParsedReplay>) + Sync)>,
+    ) -> AnyResult<Vec<AnyResult<ParsedReplay>>> {
+        let paths: Vec<AnyResult<PathBuf>> = paths.into_iter().collect();
+
+        let mut builder = ThreadPoolBuilder::new();
+        if let Some(threads) = max_concurrency {
+            builder = builder.num_threads(threads);
+        }
+        let pool = builder
+            .build()
+            .context("failed to build rayon thread pool")?;
+
+        let results = pool.install(|| {
+            paths
+                .into_par_iter()
+                .map(|entry| {
+                    let result = match entry {
+                        Ok(path) => self.parse_path(path),
+                        Err(err) => Err(err),
+                    };
+                    if let Some(on_progress) = on_progress {
+                        on_progress(&result);
+                    }
+                    result
+                })
+                .collect()
+        });
+
+        Ok(results)
     }
 }
 
@@ -132,3 +234,52 @@ pub fn expand_paths(files: &[PathBuf]) -> impl Iterator<Item = AnyResult<PathBuf
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerant_fallback_warning_fires_only_for_tolerant_mode_without_network_frames() {
+        assert!(tolerant_fallback_warning(NetworkMode::Tolerant, false).is_some());
+    }
+
+    #[test]
+    fn tolerant_fallback_warning_is_none_when_tolerant_mode_still_has_network_frames() {
+        assert_eq!(tolerant_fallback_warning(NetworkMode::Tolerant, true), None);
+    }
+
+    #[test]
+    fn tolerant_fallback_warning_is_none_for_skip_and_always() {
+        assert_eq!(tolerant_fallback_warning(NetworkMode::Skip, false), None);
+        assert_eq!(tolerant_fallback_warning(NetworkMode::Always, false), None);
+    }
+
+    #[test]
+    fn parse_paths_parallel_isolates_per_item_errors_and_reports_progress_per_input() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let parser = ReplayParser::new();
+        let paths: Vec<AnyResult<PathBuf>> = vec![
+            Ok(PathBuf::from("/nonexistent/one.replay")),
+            Err(anyhow::anyhow!("failed to expand path")),
+            Ok(PathBuf::from("/nonexistent/two.replay")),
+        ];
+
+        let progress_count = AtomicUsize::new(0);
+        let on_progress = |_: &AnyResult<ParsedReplay>| {
+            progress_count.fetch_add(1, Ordering::SeqCst);
+        };
+
+        let results = parser
+            .parse_paths_parallel(paths, Some(1), Some(&on_progress))
+            .unwrap();
+
+        // None of the three inputs can succeed (two point at files that don't exist, one is
+        // already an error), but every one of them should still show up as its own `Err`
+        // rather than the whole batch aborting after the first failure.
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(Result::is_err));
+        assert_eq!(progress_count.load(Ordering::SeqCst), 3);
+    }
+}
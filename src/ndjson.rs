@@ -0,0 +1,122 @@
+use crate::ParsedReplay;
+use anyhow::Result as AnyResult;
+use serde_json::Value;
+use std::io::Write;
+use std::sync::Mutex;
+
+impl ParsedReplay {
+    /// Writes this replay as a single line of newline-delimited JSON to `sink`. When
+    /// `include_path` is set, the originating path is embedded as a `"path"` field
+    /// alongside the replay's own fields so records stay traceable once they're part of a
+    /// stream rather than a named file.
+    pub fn write_ndjson<W: Write>(&self, sink: &mut W, include_path: bool) -> AnyResult<()> {
+        if include_path {
+            let mut value = serde_json::to_value(&self.replay)?;
+            if let Some(object) = value.as_object_mut() {
+                object.insert(
+                    "path".to_owned(),
+                    Value::String(self.path.display().to_string()),
+                );
+            }
+            serde_json::to_writer(&mut *sink, &value)?;
+        } else {
+            serde_json::to_writer(&mut *sink, &self.replay)?;
+        }
+        writeln!(sink)?;
+        Ok(())
+    }
+}
+
+/// Builds an `on_progress` callback for [`crate::ReplayParser::parse_paths_parallel`] that
+/// writes each replay to `sink` as newline-delimited JSON the moment it finishes, instead
+/// of buffering the whole batch before anything reaches the sink. Pairs with
+/// `parse_paths_parallel` so a CLI can pipe `rrrocket dir/ > replays.ndjson` and downstream
+/// tools can process records as they arrive rather than waiting for the whole directory to
+/// finish parsing. Writes are serialized behind a mutex since results can complete on, and
+/// this callback can therefore be invoked from, any worker thread. Entries that failed to
+/// parse are reported to stderr and skipped rather than aborting the stream.
+pub fn ndjson_progress_writer<W: Write + Send>(
+    sink: W,
+    include_path: bool,
+) -> impl Fn(&AnyResult<ParsedReplay>) + Sync {
+    let sink = Mutex::new(sink);
+    move |result: &AnyResult<ParsedReplay>| {
+        let mut sink = sink.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let outcome = match result {
+            Ok(parsed) => parsed.write_ndjson(&mut *sink, include_path),
+            Err(err) => {
+                eprintln!("skipping replay: {err:#}");
+                return;
+            }
+        };
+        if let Err(err) = outcome {
+            eprintln!("failed to write ndjson record: {err:#}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boxcars::Replay;
+    use std::path::PathBuf;
+
+    fn sample_parsed() -> ParsedReplay {
+        ParsedReplay {
+            path: PathBuf::from("match.replay"),
+            replay: Replay::default(),
+            warning: None,
+        }
+    }
+
+    #[test]
+    fn write_ndjson_emits_one_line_without_path() {
+        let parsed = sample_parsed();
+        let mut out = Vec::new();
+        parsed.write_ndjson(&mut out, false).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.matches('\n').count(), 1);
+        let value: Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert!(value.get("path").is_none());
+    }
+
+    #[test]
+    fn write_ndjson_embeds_path_when_requested() {
+        let parsed = sample_parsed();
+        let mut out = Vec::new();
+        parsed.write_ndjson(&mut out, true).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let value: Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(value["path"], Value::String("match.replay".to_owned()));
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::sync::Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn progress_writer_streams_each_successful_result_as_it_arrives() {
+        let buf = SharedBuf::default();
+        let handle = buf.clone();
+        let write_result = ndjson_progress_writer(buf, false);
+
+        write_result(&Ok(sample_parsed()));
+        write_result(&Err(anyhow::anyhow!("broken replay")));
+        write_result(&Ok(sample_parsed()));
+
+        let contents = handle.0.lock().unwrap();
+        let text = String::from_utf8(contents.clone()).unwrap();
+        assert_eq!(text.lines().count(), 2);
+    }
+}
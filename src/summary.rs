@@ -0,0 +1,258 @@
+use crate::{expand_directory, NetworkMode, ReplayParser};
+use anyhow::{Context, Result as AnyResult};
+use boxcars::{HeaderProp, Replay};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// A single player's name and score, extracted from a replay's `PlayerStats` header
+/// property.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerSummary {
+    pub name: String,
+    pub score: i32,
+}
+
+/// A compact, header-only summary of a replay, suitable for aggregating stats (e.g. a
+/// leaderboard) across a whole directory of replays without decoding any network data.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaySummary {
+    pub path: PathBuf,
+    pub map_name: Option<String>,
+    pub team_size: Option<i32>,
+    pub team_0_score: Option<i32>,
+    pub team_1_score: Option<i32>,
+    pub players: Vec<PlayerSummary>,
+    pub match_length_seconds: Option<f32>,
+    pub date: Option<String>,
+}
+
+/// A flattened, string-only view of a [`ReplaySummary`] for CSV output.
+#[derive(Serialize)]
+struct ReplaySummaryRow {
+    path: String,
+    map_name: String,
+    team_size: String,
+    team_0_score: String,
+    team_1_score: String,
+    match_length_seconds: String,
+    date: String,
+    players: String,
+}
+
+fn find_prop<'a>(properties: &'a [(String, HeaderProp)], key: &str) -> Option<&'a HeaderProp> {
+    properties.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+}
+
+fn prop_i32(properties: &[(String, HeaderProp)], key: &str) -> Option<i32> {
+    find_prop(properties, key).and_then(HeaderProp::as_i32)
+}
+
+fn prop_f32(properties: &[(String, HeaderProp)], key: &str) -> Option<f32> {
+    find_prop(properties, key).and_then(HeaderProp::as_f32)
+}
+
+fn prop_string(properties: &[(String, HeaderProp)], key: &str) -> Option<String> {
+    find_prop(properties, key)
+        .and_then(HeaderProp::as_string)
+        .map(ToOwned::to_owned)
+}
+
+/// Rocket League headers don't carry a literal match-length field; they record the total
+/// number of network frames and the recording rate instead, so the duration has to be
+/// derived from those.
+fn match_length_seconds(properties: &[(String, HeaderProp)]) -> Option<f32> {
+    let num_frames = prop_i32(properties, "NumFrames")? as f32;
+    let record_fps = prop_f32(properties, "RecordFPS")?;
+    (record_fps > 0.0).then(|| num_frames / record_fps)
+}
+
+fn players_from_stats(properties: &[(String, HeaderProp)]) -> Vec<PlayerSummary> {
+    find_prop(properties, "PlayerStats")
+        .and_then(HeaderProp::as_array)
+        .map(|stats| {
+            stats
+                .iter()
+                .filter_map(|player| {
+                    let name = prop_string(player, "Name")?;
+                    let score = prop_i32(player, "Score").unwrap_or_default();
+                    Some(PlayerSummary { name, score })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl ReplaySummary {
+    /// Extracts a header-only summary from an already-parsed replay. `path` is purely a
+    /// label (e.g. a file path or source URL) carried along for display and export.
+    pub fn from_replay(path: PathBuf, replay: &Replay) -> Self {
+        let properties = &replay.properties;
+        ReplaySummary {
+            map_name: prop_string(properties, "MapName"),
+            team_size: prop_i32(properties, "TeamSize"),
+            team_0_score: prop_i32(properties, "Team0Score"),
+            team_1_score: prop_i32(properties, "Team1Score"),
+            players: players_from_stats(properties),
+            match_length_seconds: match_length_seconds(properties),
+            date: prop_string(properties, "Date"),
+            path,
+        }
+    }
+
+    fn to_row(&self) -> ReplaySummaryRow {
+        ReplaySummaryRow {
+            path: self.path.display().to_string(),
+            map_name: self.map_name.clone().unwrap_or_default(),
+            team_size: self.team_size.map(|v| v.to_string()).unwrap_or_default(),
+            team_0_score: self
+                .team_0_score
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            team_1_score: self
+                .team_1_score
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            match_length_seconds: self
+                .match_length_seconds
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            date: self.date.clone().unwrap_or_default(),
+            players: self
+                .players
+                .iter()
+                .map(|p| format!("{}:{}", p.name, p.score))
+                .collect::<Vec<_>>()
+                .join(";"),
+        }
+    }
+}
+
+/// Parses every `.replay` file under `dir` in header-only mode and returns a compact
+/// summary for each, letting callers build leaderboards across a large batch of replays
+/// without paying the cost of decoding positional network data. A file that fails to parse
+/// is reported alongside its path rather than aborting the whole scan, so one corrupt
+/// replay among thousands doesn't wipe out every other summary.
+pub fn summarize_paths(dir: &Path) -> Vec<AnyResult<ReplaySummary>> {
+    let parser = ReplayParser::new().with_network_mode(NetworkMode::Skip);
+
+    expand_directory(dir)
+        .map(|path| {
+            let path = path?;
+            let parsed = parser
+                .parse_path(path.clone())
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+            Ok(ReplaySummary::from_replay(path, &parsed.replay))
+        })
+        .collect()
+}
+
+/// Serializes a batch of summaries to pretty-printed JSON.
+pub fn summaries_to_json(summaries: &[ReplaySummary]) -> AnyResult<String> {
+    Ok(serde_json::to_string_pretty(summaries)?)
+}
+
+/// Serializes a batch of summaries to CSV, one row per replay.
+pub fn summaries_to_csv(summaries: &[ReplaySummary]) -> AnyResult<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for summary in summaries {
+        writer.serialize(summary.to_row())?;
+    }
+    let bytes = writer
+        .into_inner()
+        .context("failed to finalize CSV writer")?;
+    String::from_utf8(bytes).context("CSV output was not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_summary() -> ReplaySummary {
+        ReplaySummary {
+            path: PathBuf::from("match.replay"),
+            map_name: Some("Stadium_P".to_owned()),
+            team_size: Some(3),
+            team_0_score: Some(2),
+            team_1_score: Some(5),
+            players: vec![
+                PlayerSummary {
+                    name: "Squishy".to_owned(),
+                    score: 400,
+                },
+                PlayerSummary {
+                    name: "Sadge".to_owned(),
+                    score: 250,
+                },
+            ],
+            match_length_seconds: Some(300.0),
+            date: Some("2020-01-01 12:00:00".to_owned()),
+        }
+    }
+
+    fn empty_summary() -> ReplaySummary {
+        ReplaySummary {
+            path: PathBuf::from("blank.replay"),
+            map_name: None,
+            team_size: None,
+            team_0_score: None,
+            team_1_score: None,
+            players: Vec::new(),
+            match_length_seconds: None,
+            date: None,
+        }
+    }
+
+    #[test]
+    fn match_length_seconds_divides_frames_by_record_fps() {
+        let properties = vec![
+            ("NumFrames".to_owned(), HeaderProp::Int(3000)),
+            ("RecordFPS".to_owned(), HeaderProp::Float(30.0)),
+        ];
+        assert_eq!(match_length_seconds(&properties), Some(100.0));
+    }
+
+    #[test]
+    fn match_length_seconds_is_none_without_both_properties() {
+        let properties = vec![("NumFrames".to_owned(), HeaderProp::Int(3000))];
+        assert_eq!(match_length_seconds(&properties), None);
+    }
+
+    #[test]
+    fn match_length_seconds_is_none_for_zero_record_fps() {
+        let properties = vec![
+            ("NumFrames".to_owned(), HeaderProp::Int(3000)),
+            ("RecordFPS".to_owned(), HeaderProp::Float(0.0)),
+        ];
+        assert_eq!(match_length_seconds(&properties), None);
+    }
+
+    #[test]
+    fn to_row_flattens_players_and_fills_in_missing_fields() {
+        let row = sample_summary().to_row();
+        assert_eq!(row.map_name, "Stadium_P");
+        assert_eq!(row.players, "Squishy:400;Sadge:250");
+
+        let blank_row = empty_summary().to_row();
+        assert_eq!(blank_row.map_name, "");
+        assert_eq!(blank_row.players, "");
+    }
+
+    #[test]
+    fn summaries_to_csv_includes_a_header_and_one_row_per_summary() {
+        let csv = summaries_to_csv(&[sample_summary(), empty_summary()]).unwrap();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("path,map_name"));
+        assert!(lines.next().unwrap().contains("Stadium_P"));
+        assert!(lines.next().unwrap().starts_with("blank.replay"));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn summaries_to_json_round_trips_through_serde_json() {
+        let summaries = vec![sample_summary()];
+        let json = summaries_to_json(&summaries).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["map_name"], "Stadium_P");
+        assert_eq!(value[0]["players"][0]["name"], "Squishy");
+    }
+}